@@ -54,9 +54,22 @@
 //! ```
 //!
 //! Instead you must provide an actual parameter list, just like you would with a real Rust
-//! function definition.  That, in turn, means that your function parameters can only draw values
-//! using the `any` strategy for their types.  If you want to use a custom strategy, you must
-//! create a separately named type, and have the new type's `Arbitrary` impl use that strategy:
+//! function definition.  By default, that means your function parameters draw values using the
+//! `any` strategy for their types.  You can still use a custom strategy by giving the attribute a
+//! comma-separated list of strategy expressions, one per parameter, in the same order as the
+//! parameter list:
+//!
+//! ```
+//! # use proptest::strategy::Strategy;
+//! # use proptest_attr_macro::proptest;
+//! #[proptest(33u8..100u8)]
+//! fn test_in_range(x: u8) {
+//!     assert!(x >= 33 && x < 100);
+//! }
+//! ```
+//!
+//! Alternatively, you can create a separately named type, and have the new type's `Arbitrary` impl
+//! use a custom strategy:
 //!
 //! ```
 //! # #[derive(Clone, Debug)]
@@ -93,16 +106,74 @@ use proc_macro2::Span;
 use proc_macro2::TokenStream as TokenStream2;
 use quote::quote;
 use quote::ToTokens;
+use syn::parse::Parse;
+use syn::parse::ParseStream;
+use syn::parse::Parser;
 use syn::parse_macro_input;
 use syn::parse_quote;
 use syn::punctuated::Punctuated;
 use syn::token::Comma;
+use syn::Expr;
 use syn::FnArg;
+use syn::Ident;
 use syn::Item;
+use syn::Lit;
 use syn::Pat;
 use syn::Stmt;
 use syn::Token;
 
+/// A single entry in the `#[proptest(...)]` argument list: either a per-parameter strategy
+/// expression, `_` to fall back to the `any` strategy for that parameter, or a `name = literal`
+/// runner option such as `cases = 1000`.
+enum Arg {
+    Any,
+    Strategy(Expr),
+    Option(Ident, Lit),
+}
+
+impl Parse for Arg {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(Token![_]) {
+            input.parse::<Token![_]>()?;
+            return Ok(Arg::Any);
+        }
+        if input.peek(Ident) && input.peek2(Token![=]) {
+            let name = input.parse::<Ident>()?;
+            input.parse::<Token![=]>()?;
+            let lit = input.parse::<Lit>()?;
+            return Ok(Arg::Option(name, lit));
+        }
+        input.parse().map(Arg::Strategy)
+    }
+}
+
+impl ToTokens for Arg {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        match self {
+            Arg::Any => Token![_](Span::call_site()).to_tokens(tokens),
+            Arg::Strategy(expr) => expr.to_tokens(tokens),
+            Arg::Option(name, lit) => quote! { #name = #lit }.to_tokens(tokens),
+        }
+    }
+}
+
+/// If `expr` is a call to `any_with(params)`, returns `params`. This lets a positional strategy
+/// argument opt into `Arbitrary::Parameters` support without requiring a separately named type.
+fn any_with_params(expr: &Expr) -> Option<&Expr> {
+    let call = match expr {
+        Expr::Call(call) => call,
+        _ => return None,
+    };
+    let path = match &*call.func {
+        Expr::Path(path) => &path.path,
+        _ => return None,
+    };
+    if !path.is_ident("any_with") || call.args.len() != 1 {
+        return None;
+    }
+    call.args.first()
+}
+
 /// An attribute macro that marks a function as a test case, and uses proptest's [`any`][] strategy
 /// to produce random values for each of the function's parameters.
 ///
@@ -116,8 +187,102 @@ use syn::Token;
 ///     assert!(x == y || x != y);
 /// }
 /// ```
+///
+/// You can override the strategy used for some or all of the parameters by giving the attribute a
+/// comma-separated list of strategy expressions, positionally matched up with the parameter list.
+/// Each expression must be a value implementing `Strategy<Value = T>` for the corresponding
+/// parameter's type `T`:
+///
+/// ```
+/// # use proptest::strategy::Strategy;
+/// # use proptest_attr_macro::proptest;
+/// #[proptest(33u8..100u8)]
+/// fn test_in_range(x: u8) {
+///     assert!(x >= 33 && x < 100);
+/// }
+/// ```
+///
+/// Use `_` in place of an expression to fall back to the `any` strategy for just that parameter,
+/// so you don't have to spell out a strategy for every parameter when only some of them need one:
+///
+/// ```
+/// # use proptest::strategy::Strategy;
+/// # use proptest_attr_macro::proptest;
+/// #[proptest(_, 0u8..10u8)]
+/// fn test_mixed_strategies(x: u32, y: u8) {
+///     assert!(y < 10);
+/// }
+/// ```
+///
+/// You can also tune the underlying [`TestRunner`][]'s [`Config`][] by passing `name = literal`
+/// options, in any order and alongside the positional strategy expressions above:
+///
+/// ```
+/// # use proptest_attr_macro::proptest;
+/// #[proptest(cases = 1000, max_shrink_iters = 5000, max_local_rejects = 100)]
+/// fn test_with_more_cases(x: u32) {
+///     assert!(x == x);
+/// }
+/// ```
+///
+/// [`TestRunner`]: https://docs.rs/proptest/*/proptest/test_runner/struct.TestRunner.html
+/// [`Config`]: https://docs.rs/proptest/*/proptest/test_runner/struct.Config.html
+///
+/// Some `Arbitrary` impls take a `Parameters` value (via [`any_with`][]) to influence generation,
+/// e.g. to bound the size or range of the generated value. Wrap a positional strategy argument in
+/// `any_with(...)` to supply it:
+///
+/// ```
+/// # use proptest::arbitrary::Arbitrary;
+/// # use proptest::strategy::BoxedStrategy;
+/// # use proptest::strategy::Strategy;
+/// # #[derive(Clone, Debug)]
+/// struct Bounded { value: i32 }
+///
+/// impl Arbitrary for Bounded {
+///     type Parameters = i32;
+///     type Strategy = BoxedStrategy<Bounded>;
+///
+///     fn arbitrary_with(max: i32) -> Self::Strategy {
+///         (0..max).prop_map(|value| Bounded { value }).boxed()
+///     }
+/// }
+///
+/// # use proptest_attr_macro::proptest;
+/// #[proptest(any_with(100))]
+/// fn test_bounded(b: Bounded) {
+///     assert!(b.value < 100);
+/// }
+/// ```
+///
+/// [`any_with`]: https://docs.rs/proptest/*/proptest/arbitrary/fn.any_with.html
+///
+/// The function body runs in a context that returns
+/// `Result<(), proptest::test_runner::TestCaseError>`, so you can `?`-propagate a `TestCaseError`
+/// out of a helper function instead of panicking:
+///
+/// ```
+/// use proptest::test_runner::TestCaseError;
+/// # use proptest_attr_macro::proptest;
+/// fn check_even(x: u32) -> Result<(), TestCaseError> {
+///     if x % 2 == 0 {
+///         Ok(())
+///     } else {
+///         Err(TestCaseError::Fail("expected an even number".into()))
+///     }
+/// }
+///
+/// #[proptest]
+/// fn test_uses_question_mark(x: u32) {
+///     check_even(x.wrapping_mul(2))?;
+/// }
+/// ```
 #[proc_macro_attribute]
-pub fn proptest(_args: TokenStream, input: TokenStream) -> TokenStream {
+pub fn proptest(args: TokenStream, input: TokenStream) -> TokenStream {
+    let args = match Punctuated::<Arg, Comma>::parse_terminated.parse(args) {
+        Ok(args) => args,
+        Err(err) => return err.to_compile_error().into(),
+    };
     let item = parse_macro_input!(input as Item);
     match item {
         Item::Fn(mut func) => {
@@ -131,10 +296,38 @@ pub fn proptest(_args: TokenStream, input: TokenStream) -> TokenStream {
             }
             func_body.stmts.push(parse_quote! { return Ok(()); });
 
+            let param_count = func
+                .sig
+                .inputs
+                .iter()
+                .filter(|arg| matches!(arg, FnArg::Typed(typed) if matches!(*typed.pat, Pat::Ident(_))))
+                .count();
+
+            let mut config_options = Vec::new();
+            let mut strategy_args = Vec::new();
+            for arg in args {
+                match arg {
+                    Arg::Option(name, lit) => config_options.push(quote! { config.#name = #lit; }),
+                    other => strategy_args.push(other),
+                }
+            }
+
+            if !strategy_args.is_empty() && strategy_args.len() != param_count {
+                let msg = format!(
+                    "expected {} strategy expression(s), one per parameter, but found {}",
+                    param_count,
+                    strategy_args.len()
+                );
+                return syn::Error::new_spanned(quote! { #(#strategy_args),* }, msg)
+                    .to_compile_error()
+                    .into();
+            }
+
             let mut formal_params = TupleList::new();
             let mut actual_params = Punctuated::<_, Comma>::new();
             let mut names = TupleList::new();
             let mut strategies = TupleList::new();
+            let mut strategy_args = strategy_args.into_iter();
             for arg in func.sig.inputs.iter() {
                 if let FnArg::Typed(typed) = arg {
                     if let Pat::Ident(name) = &*typed.pat {
@@ -142,7 +335,17 @@ pub fn proptest(_args: TokenStream, input: TokenStream) -> TokenStream {
                         formal_params.push(name.ident.clone());
                         actual_params.push(name.ident.clone());
                         names.push(name.ident.to_string());
-                        strategies.push(quote! { ::proptest::arbitrary::any::<#ty>() });
+                        let strategy = match strategy_args.next() {
+                            Some(Arg::Any) | None => {
+                                quote! { ::proptest::arbitrary::any::<#ty>() }
+                            }
+                            Some(Arg::Strategy(expr)) => match any_with_params(&expr) {
+                                Some(params) => quote! { ::proptest::arbitrary::any_with::<#ty>(#params) },
+                                None => quote! { #expr },
+                            },
+                            Some(Arg::Option(..)) => unreachable!("options were filtered out above"),
+                        };
+                        strategies.push(strategy);
                     }
                 }
             }
@@ -153,6 +356,7 @@ pub fn proptest(_args: TokenStream, input: TokenStream) -> TokenStream {
                 let mut config = ::proptest::test_runner::Config::default();
                 config.test_name = Some(concat!(module_path!(), "::", stringify!(#func_name)));
                 config.source_file = Some(file!());
+                #(#config_options)*
                 let mut runner = ::proptest::test_runner::TestRunner::new(config);
                 let names = #names;
                 match runner.run(
@@ -160,7 +364,9 @@ pub fn proptest(_args: TokenStream, input: TokenStream) -> TokenStream {
                         #strategies,
                         |values| ::proptest::sugar::NamedArguments(names, values),
                     ),
-                    |::proptest::sugar::NamedArguments(_, #formal_params)| {
+                    |::proptest::sugar::NamedArguments(_, #formal_params)|
+                        -> ::std::result::Result<(), ::proptest::test_runner::TestCaseError>
+                    {
                         #func_body
                     }
                 ) {
@@ -1,4 +1,8 @@
+use proptest::arbitrary::Arbitrary;
 use proptest::prelude::prop_compose;
+use proptest::strategy::BoxedStrategy;
+use proptest::strategy::Strategy;
+use proptest::test_runner::TestCaseError;
 use proptest_attr_macro::proptest;
 
 #[proptest(33u8..100u8)]
@@ -23,6 +27,43 @@ fn multiple_inline_strategies(x: u8, y: u8) {
     assert!(y >= 6 && y < 10);
 }
 
+#[proptest(cases = 17, max_shrink_iters = 1000, max_local_rejects = 100)]
+fn runner_config_options(x: u8) {
+    assert!(x == x);
+}
+
+#[derive(Clone, Debug)]
+struct Bounded {
+    value: i32,
+}
+
+impl Arbitrary for Bounded {
+    type Parameters = i32;
+    type Strategy = BoxedStrategy<Bounded>;
+
+    fn arbitrary_with(max: i32) -> Self::Strategy {
+        (0..max).prop_map(|value| Bounded { value }).boxed()
+    }
+}
+
+#[proptest(any_with(100))]
+fn any_with_params(b: Bounded) {
+    assert!(b.value < 100);
+}
+
+fn check_even(x: u32) -> Result<(), TestCaseError> {
+    if x % 2 == 0 {
+        Ok(())
+    } else {
+        Err(TestCaseError::Fail("expected an even number".into()))
+    }
+}
+
+#[proptest]
+fn question_mark_propagation(x: u32) {
+    check_even(x.wrapping_mul(2))?;
+}
+
 prop_compose! {
   fn range(from: u8, to: u8)
                        (integer in from..to)